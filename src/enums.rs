@@ -0,0 +1,151 @@
+//! Enums: a type that says a value is one of a fixed set of
+//! possibilities, each of which can carry its own data.
+
+/// A C-like enum with no associated data.
+///
+/// ```
+/// use prolangs_guide::enums::{route, IpAddressVariant};
+///
+/// route(IpAddressVariant::V6);
+/// ```
+pub enum IpAddressVariant {
+    V4,
+    V6,
+}
+
+pub fn route(_ip_variant: IpAddressVariant) {}
+
+/// Each variant can carry different data -- here `IpAddress::V4`
+/// stores four octets and `IpAddress::V6` stores a `String`.
+///
+/// ```
+/// use prolangs_guide::enums::IpAddress;
+///
+/// let home = IpAddress::V4(127, 0, 0, 1);
+/// let loopback = IpAddress::V6(String::from("::1"));
+///
+/// match home {
+///     IpAddress::V4(a, b, c, d) => assert_eq!((a, b, c, d), (127, 0, 0, 1)),
+///     IpAddress::V6(_) => unreachable!(),
+/// }
+/// # let _ = loopback;
+/// ```
+pub enum IpAddress {
+    V4(u8, u8, u8, u8),
+    V6(String),
+}
+
+// A variant can even hold another type entirely, including a struct.
+pub struct Ipv4Addr {
+    pub octets: [u8; 4],
+}
+
+pub struct Ipv6Addr {
+    pub segments: [u16; 8],
+}
+
+pub enum IpAddr {
+    V4(Ipv4Addr),
+    V6(Ipv6Addr),
+}
+
+/// An enum whose variants store different amounts and types of
+/// values -- equivalent to four unrelated structs (`QuitMessage`,
+/// a `MoveMessage { x, y }`, `WriteMessage(String)`, and
+/// `ChangeColorMessage(i32, i32, i32)`), but grouped as one type so
+/// that functions can accept "any kind of Message".
+///
+/// ```
+/// use prolangs_guide::enums::Message;
+///
+/// let m = Message::Write(String::from("Devil May Cry"));
+/// assert_eq!(m.call(), "wrote: Devil May Cry");
+/// ```
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+impl Message {
+    pub fn call(&self) -> String {
+        match self {
+            Message::Quit => "quit".to_string(),
+            Message::Move { x, y } => format!("moved to ({x}, {y})"),
+            Message::Write(text) => format!("wrote: {text}"),
+            Message::ChangeColor(r, g, b) => format!("color set to ({r}, {g}, {b})"),
+        }
+    }
+}
+
+// Rust has no null. In languages with null, a variable is always
+// either null or not-null. `Option<T>`, defined in the standard
+// library, encodes "a value that may or may not be present" instead,
+// and is so common that it (along with its `Some`/`None` variants) is
+// part of the prelude -- no explicit import needed.
+//
+//     enum Option<T> {
+//         None,
+//         Some(T),
+//     }
+//
+// `Some(5)` lets Rust infer `Option<i32>`; an absent value still needs
+// an explicit annotation, since the compiler can't infer a type from
+// `None` alone: `let absent_number: Option<i32> = None;`
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsState {
+    Alabama,
+    Alaska,
+    // --snip--
+}
+
+pub enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter(UsState),
+}
+
+/// `match` is exhaustive: every possibility must be handled (or
+/// covered by a catch-all), or the code fails to compile. Binding a
+/// variable inside a pattern -- `Coin::Quarter(state)` below -- is how
+/// data is extracted out of an enum variant.
+///
+/// ```
+/// use prolangs_guide::enums::{value_in_cents, Coin, UsState};
+///
+/// assert_eq!(value_in_cents(Coin::Penny), 1);
+/// assert_eq!(value_in_cents(Coin::Quarter(UsState::Alaska)), 25);
+/// ```
+pub fn value_in_cents(coin: Coin) -> u8 {
+    match coin {
+        Coin::Penny => 1,
+        Coin::Nickel => 5,
+        Coin::Dime => 10,
+        Coin::Quarter(state) => {
+            println!("State quarter from {state:?}");
+            25
+        }
+    }
+}
+
+/// Matching on `Option<T>`: add one to the contained value if
+/// present, otherwise pass `None` straight through.
+///
+/// ```
+/// use prolangs_guide::enums::plus_one;
+///
+/// assert_eq!(plus_one(Some(5)), Some(6));
+/// assert_eq!(plus_one(None), None);
+/// ```
+// written as a match rather than `x.map(|i| i + 1)` to show how Some
+// is destructured explicitly
+#[allow(clippy::manual_map)]
+pub fn plus_one(x: Option<i32>) -> Option<i32> {
+    match x {
+        None => None,
+        Some(i) => Some(i + 1),
+    }
+}
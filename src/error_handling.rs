@@ -0,0 +1,128 @@
+//! Error handling with `Result<T, E>`.
+//!
+//! `Option<T>` models a value that might be absent; `Result<T, E>`
+//! models an operation that might fail, carrying information about
+//! *why* it failed in its `Err` variant.
+
+/// A custom error enum describing what can go wrong parsing a config
+/// line.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    Empty,
+    NotANumber(String),
+}
+
+/// Matching directly on `Ok`/`Err`.
+///
+/// ```
+/// use prolangs_guide::error_handling::{parse_count, ConfigError};
+///
+/// assert_eq!(parse_count("4"), Ok(4));
+/// assert_eq!(parse_count(""), Err(ConfigError::Empty));
+/// assert_eq!(parse_count("nope"), Err(ConfigError::NotANumber("nope".to_string())));
+/// ```
+pub fn parse_count(input: &str) -> Result<u32, ConfigError> {
+    if input.is_empty() {
+        return Err(ConfigError::Empty);
+    }
+
+    match input.trim().parse::<u32>() {
+        Ok(n) => Ok(n),
+        Err(_) => Err(ConfigError::NotANumber(input.to_string())),
+    }
+}
+
+/// The `?` operator unwraps an `Ok` value, or returns the `Err` early
+/// from the enclosing function -- as long as that function also
+/// returns a `Result` with a compatible error type.
+///
+/// ```
+/// use prolangs_guide::error_handling::{total_count, ConfigError};
+///
+/// assert_eq!(total_count(&["1", "2", "3"]), Ok(6));
+/// assert_eq!(total_count(&["1", ""]), Err(ConfigError::Empty));
+/// ```
+pub fn total_count(lines: &[&str]) -> Result<u32, ConfigError> {
+    let mut total = 0;
+
+    for line in lines {
+        let n = parse_count(line)?;
+        total += n;
+    }
+
+    Ok(total)
+}
+
+// the above is shorthand for matching on every call site -- written
+// out by hand here to show what `?` expands to
+#[allow(clippy::question_mark)]
+pub fn total_count_explicit(lines: &[&str]) -> Result<u32, ConfigError> {
+    let mut total = 0;
+
+    for line in lines {
+        let n = match parse_count(line) {
+            Ok(n) => n,
+            Err(e) => return Err(e),
+        };
+        total += n;
+    }
+
+    Ok(total)
+}
+
+/// `Option::ok_or` turns a missing value into an `Err` with the given
+/// error, and a present value into `Ok`.
+///
+/// ```
+/// use prolangs_guide::error_handling::{option_to_result, ConfigError};
+///
+/// assert_eq!(option_to_result(Some(4)), Ok(4));
+/// assert_eq!(option_to_result(None), Err(ConfigError::Empty));
+/// ```
+pub fn option_to_result(value: Option<u32>) -> Result<u32, ConfigError> {
+    value.ok_or(ConfigError::Empty)
+}
+
+/// `Result::ok` discards the error and turns a `Result` into an
+/// `Option`, useful when only success/failure matters, not why.
+///
+/// ```
+/// use prolangs_guide::error_handling::result_to_option;
+///
+/// assert_eq!(result_to_option("4"), Some(4));
+/// assert_eq!(result_to_option("nope"), None);
+/// ```
+pub fn result_to_option(input: &str) -> Option<u32> {
+    parse_count(input).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_and_question_mark_versions_agree() {
+        let lines = ["1", "2", "3"];
+        assert_eq!(total_count(&lines), total_count_explicit(&lines));
+    }
+
+    #[test]
+    fn map_map_err_and_and_then() {
+        let doubled = parse_count("4").map(|n| n * 2);
+        assert_eq!(doubled, Ok(8));
+
+        let renamed = parse_count("").map_err(|_| "config was empty");
+        assert_eq!(renamed, Err("config was empty"));
+
+        let chained = parse_count("4").and_then(|n| {
+            if n > 0 { Ok(n) } else { Err(ConfigError::Empty) }
+        });
+        assert_eq!(chained, Ok(4));
+    }
+
+    #[test]
+    fn unwrap_or_and_unwrap_or_else() {
+        assert_eq!(parse_count("nope").unwrap_or(0), 0);
+        assert_eq!(parse_count("nope").unwrap_or_else(|e| if e == ConfigError::Empty { 1 } else { 0 }), 0);
+    }
+}
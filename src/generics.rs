@@ -0,0 +1,150 @@
+//! Generics and trait bounds: the other half of the monomorphization
+//! story started in [`crate::traits`] -- `<T: Greet>` and `&impl Greet`
+//! compile down to the same specialized code per concrete type.
+
+use crate::traits::{Greet, Person, Robot};
+
+/// A generic function with a trait bound: for every concrete `T` that
+/// implements `Greet`, the compiler generates a specialized version of
+/// this function, exactly as `fn greet_someone(greeter: &impl Greet)`
+/// does in [`crate::traits`] -- `<T: Greet>` is just the named form of
+/// the same bound.
+///
+/// ```
+/// use prolangs_guide::generics::greet_all;
+/// use prolangs_guide::traits::Person;
+///
+/// let people = vec![
+///     Person { name: "Alice".to_string() },
+///     Person { name: "Bob".to_string() },
+/// ];
+/// assert_eq!(greet_all(&people), vec!["My name is Alice", "My name is Bob"]);
+/// ```
+pub fn greet_all<T: Greet>(items: &[T]) -> Vec<String> {
+    items.iter().map(|item| item.say_hello()).collect()
+}
+
+pub trait Named {
+    fn name(&self) -> String;
+}
+
+impl Named for Person {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Multiple bounds joined with `+`: `T` must implement both `Greet`
+/// and `Named`.
+///
+/// ```
+/// use prolangs_guide::generics::greet_by_name;
+/// use prolangs_guide::traits::Person;
+///
+/// let person = Person { name: "Alice".to_string() };
+/// assert_eq!(greet_by_name(&person), "Alice says: My name is Alice");
+/// ```
+pub fn greet_by_name<T: Greet + Named>(item: &T) -> String {
+    format!("{} says: {}", item.name(), item.say_hello())
+}
+
+/// The same bound can be spelled with a `where` clause, which reads
+/// better once there are several type parameters or several bounds
+/// each.
+///
+/// ```
+/// use prolangs_guide::generics::greet_by_name_where;
+/// use prolangs_guide::traits::Person;
+///
+/// let person = Person { name: "Bob".to_string() };
+/// assert_eq!(greet_by_name_where(&person), "Bob says: My name is Bob");
+/// ```
+pub fn greet_by_name_where<T>(item: &T) -> String
+where
+    T: Greet + Named,
+{
+    format!("{} says: {}", item.name(), item.say_hello())
+}
+
+/// An associated type lets a trait define an output type per
+/// implementor, instead of a generic parameter the caller must
+/// supply -- this is how the standard library's `Iterator` works.
+///
+/// ```
+/// use prolangs_guide::generics::{Counter, CountingIterator};
+///
+/// let mut counter = Counter::new(3);
+/// let mut seen = Vec::new();
+/// while let Some(n) = counter.next_item() {
+///     seen.push(n);
+/// }
+/// assert_eq!(seen, vec![1, 2, 3]);
+/// ```
+pub trait CountingIterator {
+    type Item;
+
+    fn next_item(&mut self) -> Option<Self::Item>;
+}
+
+pub struct Counter {
+    count: u32,
+    limit: u32,
+}
+
+impl Counter {
+    pub fn new(limit: u32) -> Counter {
+        Counter { count: 0, limit }
+    }
+}
+
+impl CountingIterator for Counter {
+    type Item = u32;
+
+    fn next_item(&mut self) -> Option<u32> {
+        if self.count < self.limit {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+/// A supertrait requires implementors to also implement another
+/// trait -- `Loud: Greet` means every `Loud` is also a `Greet`, so
+/// `Loud` methods can call `Greet` methods on `self`.
+///
+/// ```
+/// use prolangs_guide::generics::Loud;
+/// use prolangs_guide::traits::Person;
+///
+/// let person = Person { name: "Alice".to_string() };
+/// assert_eq!(person.shout(), "MY NAME IS ALICE!");
+/// ```
+pub trait Loud: Greet {
+    fn shout(&self) -> String {
+        format!("{}!", self.say_hello().to_uppercase())
+    }
+}
+
+impl Loud for Robot {}
+impl Loud for Person {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greet_all_matches_dynamic_dispatch() {
+        let robots = vec![Robot, Robot];
+        assert_eq!(greet_all(&robots), vec!["Default hello", "Default hello"]);
+
+        let dynamic: Vec<String> = robots.iter().map(|r| (r as &dyn Greet).say_hello()).collect();
+        assert_eq!(greet_all(&robots), dynamic);
+    }
+
+    #[test]
+    fn supertrait_default_method() {
+        assert_eq!(Robot.shout(), "DEFAULT HELLO!");
+    }
+}
@@ -0,0 +1,15 @@
+//! Runnable notes on Rust, one topic per module.
+//!
+//! The examples in doc comments are doctests and the rest of the
+//! runnable examples are `#[cfg(test)]` unit tests, so `cargo test`
+//! (and `cargo doc`) verify that they have not drifted out of sync with
+//! the compiler. Short snippets quoted inside plain `//` comments are
+//! not compiled.
+
+pub mod enums;
+pub mod pattern_matching;
+pub mod traits;
+pub mod generics;
+pub mod lifetimes;
+pub mod error_handling;
+pub mod ownership_closures;
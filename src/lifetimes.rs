@@ -0,0 +1,118 @@
+//! Lifetimes: the scope for which a reference is valid.
+//!
+//! Most lifetimes are implicit and inferred, just like most types are.
+//! Annotations are only needed when the lifetimes of several
+//! references could be related in more than one way, so the borrow
+//! checker needs help picking the right relationship.
+
+use crate::traits::Greet;
+
+/// `'a` says the returned `&dyn Greet` is valid for exactly as long as
+/// the borrowed `creature` is valid, so callers can't keep the trait
+/// object around after `creature` itself goes out of scope.
+///
+/// ```
+/// use prolangs_guide::lifetimes::get_greeter;
+/// use prolangs_guide::traits::{Greet, Person};
+///
+/// let person = Person { name: "Rust".to_string() };
+/// let greeter = get_greeter(&person);
+/// assert_eq!(greeter.say_hello(), "My name is Rust");
+/// ```
+// written with an explicit 'a for illustration, even though the
+// elision rules below would let the compiler infer it (see
+// get_greeter_elided)
+#[allow(clippy::needless_lifetimes)]
+pub fn get_greeter<'a>(creature: &'a impl Greet) -> &'a dyn Greet {
+    creature
+}
+
+// Elision rules let the compiler fill 'a in for you in common cases:
+// 1. each elided input reference gets its own lifetime parameter;
+// 2. with exactly one input lifetime, every elided output lifetime
+//    is assigned that one lifetime;
+// 3. with a `&self`/`&mut self` parameter, every elided output
+//    lifetime is assigned self's lifetime.
+//
+// get_greeter above matches rule 2 (one input lifetime, tied straight
+// to the output), so the annotation can actually be elided:
+pub fn get_greeter_elided(creature: &impl Greet) -> &dyn Greet {
+    creature
+}
+
+/// A struct holding a reference needs a lifetime parameter on the
+/// struct itself, so the compiler knows it can't outlive what it
+/// borrows.
+///
+/// ```
+/// use prolangs_guide::lifetimes::Announcement;
+/// use prolangs_guide::traits::{Greet, Person};
+///
+/// let person = Person { name: "Ferris".to_string() };
+/// let announcement = Announcement { greeter: &person };
+/// assert_eq!(announcement.announce(), "Announcing: My name is Ferris");
+/// ```
+pub struct Announcement<'a> {
+    pub greeter: &'a dyn Greet,
+}
+
+impl<'a> Announcement<'a> {
+    pub fn announce(&self) -> String {
+        format!("Announcing: {}", self.greeter.say_hello())
+    }
+}
+
+/// The same applies to an enum variant holding a reference.
+///
+/// ```
+/// use prolangs_guide::lifetimes::{describe_broadcast, Broadcast};
+/// use prolangs_guide::traits::Person;
+///
+/// let person = Person { name: "Ferris".to_string() };
+/// let broadcast = Broadcast::Message(&person);
+/// assert_eq!(describe_broadcast(broadcast), "My name is Ferris");
+/// assert_eq!(describe_broadcast(Broadcast::Silence), "...");
+/// ```
+pub enum Broadcast<'a> {
+    Silence,
+    Message(&'a dyn Greet),
+}
+
+pub fn describe_broadcast(broadcast: Broadcast) -> String {
+    match broadcast {
+        Broadcast::Silence => "...".to_string(),
+        Broadcast::Message(greeter) => greeter.say_hello(),
+    }
+}
+
+/// `'static` means a reference is valid for the entire program --
+/// every string literal has this lifetime, since its text is baked
+/// directly into the binary.
+///
+/// ```
+/// use prolangs_guide::lifetimes::ANNOUNCEMENT;
+///
+/// assert_eq!(ANNOUNCEMENT, "Always valid for the program's life");
+/// ```
+pub static ANNOUNCEMENT: &str = "Always valid for the program's life";
+
+// Box<dyn Trait> needs no lifetime annotation because it owns its
+// data: `fn get_boxed_greeter() -> Box<dyn Greet> { Box::new(Robot) }`.
+// Reach for 'static only when data really does live for the whole
+// program (string literals, globals, leaked memory) -- prefer a
+// shorter, explicit lifetime whenever one will do.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Person;
+
+    #[test]
+    fn elided_and_explicit_signatures_agree() {
+        let person = Person { name: "Rust".to_string() };
+        assert_eq!(
+            get_greeter(&person).say_hello(),
+            get_greeter_elided(&person).say_hello(),
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Closures and ownership: the `move` closure behind
+//! [`crate::pattern_matching::drain_channel`], and how trait objects
+//! let closures join the heterogeneous-collection trick used for
+//! `Box<dyn Animal>` in [`crate::traits`].
+
+use std::sync::mpsc;
+use std::thread;
+
+// The three closure traits, from least to most restrictive:
+// - `FnOnce`: can be called once; every closure implements at least
+//             this, since calling a closure always implements FnOnce.
+// - `FnMut`:  can be called more than once, and may mutate captured
+//             state between calls.
+// - `Fn`:     can be called more than once without mutating anything
+//             it captured.
+//
+// A closure that moves a captured value out of itself (e.g. returns
+// owned data it captured) is only `FnOnce`; one that only reads what
+// it captured is `Fn`.
+
+/// A plain closure borrows what it captures.
+///
+/// ```
+/// use prolangs_guide::ownership_closures::borrowing_closure_example;
+///
+/// assert_eq!(borrowing_closure_example(), "Rust");
+/// ```
+pub fn borrowing_closure_example() -> String {
+    let name = String::from("Rust");
+    let describe = || name.clone();
+    describe()
+}
+
+/// `move` forces every captured variable to be taken by value into
+/// the closure, instead of borrowed -- required whenever the closure
+/// must outlive the scope it was created in, such as one handed off
+/// to a spawned thread.
+///
+/// ```
+/// use prolangs_guide::ownership_closures::move_closure_example;
+///
+/// assert_eq!(move_closure_example(), "Rust");
+/// ```
+pub fn move_closure_example() -> String {
+    let name = String::from("Rust");
+    let describe = move || name;   // `name` is moved in, not borrowed
+    // `name` is no longer usable here -- it's owned by `describe` now
+    describe()
+}
+
+/// The `while let Ok(v) = rx.recv()` channel example from
+/// [`crate::pattern_matching::drain_channel`], expanded: `tx` is
+/// `move`d into the spawned thread because the thread may run after
+/// `produce_and_consume` would otherwise have returned, so it must own
+/// `tx` rather than borrow it.
+///
+/// ```
+/// use prolangs_guide::ownership_closures::produce_and_consume;
+///
+/// assert_eq!(produce_and_consume(vec![1, 2, 3]), vec![1, 2, 3]);
+/// ```
+pub fn produce_and_consume(values: Vec<i32>) -> Vec<i32> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for val in values {
+            tx.send(val).unwrap();
+        }
+        // tx is dropped here, which is what makes rx.recv() finally
+        // return an Err and the while let loop below stop
+    });
+
+    let mut received = Vec::new();
+    while let Ok(v) = rx.recv() {
+        received.push(v);
+    }
+    received
+}
+
+/// Closures that capture different environments still share the same
+/// type once boxed as `dyn Fn(i32) -> i32`, so they can live together
+/// in one `Vec` -- the same heterogeneous-collection idea as
+/// `Vec<Box<dyn Animal>>` in [`crate::traits`], just for behavior
+/// instead of a named trait.
+///
+/// ```
+/// use prolangs_guide::ownership_closures::apply_all;
+///
+/// let offset = 10;
+/// let pipeline: Vec<Box<dyn Fn(i32) -> i32>> = vec![
+///     Box::new(|x| x * 2),
+///     Box::new(move |x| x + offset),
+///     Box::new(|x| x - 1),
+/// ];
+///
+/// assert_eq!(apply_all(&pipeline, 5), 19); // (5*2) + 10 - 1
+/// ```
+pub fn apply_all(pipeline: &[Box<dyn Fn(i32) -> i32>], input: i32) -> i32 {
+    pipeline.iter().fold(input, |acc, f| f(acc))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn fn_mut_closure_accumulates_across_calls() {
+        let mut total = 0;
+        let mut add = |n: i32| total += n;
+
+        add(1);
+        add(2);
+        add(3);
+
+        assert_eq!(total, 6);
+    }
+
+    #[test]
+    fn fn_once_closure_consumes_captured_value() {
+        let name = String::from("Ferris");
+        let take = move || name;   // only callable once: it moves `name` out
+
+        assert_eq!(take(), "Ferris");
+    }
+}
@@ -0,0 +1,332 @@
+//! Pattern matching: `match`, `if let`, `while let`, and all the
+//! places a pattern can destructure a value.
+
+use std::sync::mpsc;
+use std::thread;
+
+// match arms
+//
+//     match VALUE {
+//         PATTERN => EXPRESSION,
+//         PATTERN => EXPRESSION,
+//     }
+
+/// `if let` / `else if` / `else if let` can all be mixed in one chain.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::choose_background;
+///
+/// assert_eq!(choose_background(Some("red"), false, "34"), "red");
+/// assert_eq!(choose_background(None, true, "34"), "green");
+/// assert_eq!(choose_background(None, false, "34"), "purple");
+/// assert_eq!(choose_background(None, false, "20"), "orange");
+/// assert_eq!(choose_background(None, false, "nope"), "blue");
+/// ```
+pub fn choose_background(favorite_color: Option<&str>, is_tuesday: bool, age: &str) -> &'static str {
+    let age: Result<u8, _> = age.parse();
+
+    if let Some(_color) = favorite_color {
+        "red"
+    } else if is_tuesday {
+        "green"
+    } else if let Ok(age) = age {
+        if age > 30 {
+            "purple"
+        } else {
+            "orange"
+        }
+    } else {
+        "blue"
+    }
+}
+
+/// `while let` runs a loop for as long as a pattern keeps matching --
+/// here, draining values sent across a channel from a spawned thread.
+/// The `move` closure takes ownership of `tx` so it can outlive the
+/// function that spawned the thread.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::drain_channel;
+///
+/// assert_eq!(drain_channel(), vec![1, 2, 3]);
+/// ```
+pub fn drain_channel() -> Vec<i32> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for val in [1, 2, 3] {
+            tx.send(val).unwrap();
+        }
+    });
+
+    let mut received = Vec::new();
+    while let Ok(value) = rx.recv() {
+        received.push(value);
+    }
+    received
+}
+
+/// `for` loops pattern-match too: `(index, value)` destructures the
+/// tuples that `enumerate` yields.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::indexed;
+///
+/// assert_eq!(indexed(&['a', 'b', 'c']), vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+/// ```
+pub fn indexed(v: &[char]) -> Vec<(usize, char)> {
+    v.iter().enumerate().map(|(index, &value)| (index, value)).collect()
+}
+
+// a `let` statement is formally `let PATTERN = EXPRESSION;`, so it can
+// destructure tuples directly: `let (x, y, z) = (2, 3, 4);`
+
+// function parameters are patterns too
+pub fn sum_pair(&(x, y): &(i32, i32)) -> i32 {
+    x + y
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Named variables in a pattern shadow any outer variable of the
+/// same name within the arm.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::describe;
+///
+/// assert_eq!(describe(50), "fifty");
+/// assert_eq!(describe(7), "other: 7");
+/// ```
+pub fn describe(x: i32) -> String {
+    match x {
+        50 => "fifty".to_string(),
+        k => format!("other: {k}"),
+    }
+}
+
+/// Multiple patterns with `|`, and inclusive ranges with `..=`.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::classify;
+///
+/// assert_eq!(classify(1), "one or two");
+/// assert_eq!(classify(4), "between 1 and 5");
+/// assert_eq!(classify(9), "other");
+/// ```
+pub fn classify(x: i32) -> &'static str {
+    match x {
+        1 | 2 => "one or two",
+        3..=5 => "between 1 and 5",
+        _ => "other",
+    }
+}
+
+/// Destructuring a struct: field names on the left of `:` select the
+/// field, the name on the right is the new binding
+/// (`let Point { x: a, y: b } = p;`).
+///
+/// ```
+/// use prolangs_guide::pattern_matching::{destructure_point, Point};
+///
+/// assert_eq!(destructure_point(Point { x: 1, y: 2 }), (1, 2));
+/// ```
+pub fn destructure_point(p: Point) -> (i32, i32) {
+    let Point { x: a, y: b } = p;
+    (a, b)
+}
+
+/// ```
+/// use prolangs_guide::pattern_matching::{describe_point, Point};
+///
+/// assert_eq!(describe_point(Point { x: 3, y: 0 }), "On x-axis at 3");
+/// assert_eq!(describe_point(Point { x: 0, y: 5 }), "On y-axis at 5");
+/// assert_eq!(describe_point(Point { x: 1, y: 1 }), "On neither axis");
+/// ```
+pub fn describe_point(p: Point) -> String {
+    match p {
+        Point { x, y: 0 } => format!("On x-axis at {x}"),
+        Point { x: 0, y } => format!("On y-axis at {y}"),
+        Point { x: _, y: _ } => "On neither axis".to_string(),
+    }
+}
+
+pub enum Shade {
+    Rgb(i32, i32, i32),
+    Hsv(i32, i32, i32),
+}
+
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(Shade),
+}
+
+/// Nested enum patterns can destructure straight through to the
+/// innermost data in one match arm.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::{describe_message, Message, Shade};
+///
+/// let msg = Message::ChangeColor(Shade::Rgb(0, 24, 72));
+/// assert_eq!(describe_message(msg), "rgb: 0, 24, 72");
+///
+/// let msg = Message::Move { x: 1, y: 2 };
+/// assert_eq!(describe_message(msg), "moved to x=1");
+/// ```
+pub fn describe_message(msg: Message) -> String {
+    match msg {
+        Message::ChangeColor(Shade::Rgb(r, g, b)) => format!("rgb: {r}, {g}, {b}"),
+        Message::ChangeColor(Shade::Hsv(h, s, v)) => format!("hsv: {h}, {s}, {v}"),
+        Message::Move { x, y: _ } => format!("moved to x={x}"),
+        _ => "don't care".to_string(),
+    }
+}
+
+// more destructuring, mixing nested tuples and structs
+pub fn feet_and_point() -> ((i32, i32), Point) {
+    let p = Point { x: 12, y: 14 };
+    let ((feet, inches), point) = ((5, 11), p);
+    ((feet, inches), point)
+}
+
+// ignore a whole parameter with _ (the argument is still passed in,
+// it just never gets a name in the body)
+pub fn only_uses_y(_: i32, y: i32) -> i32 {
+    y
+}
+
+/// `Some(_)` ignores the contents of a variant while still requiring
+/// the value be `Some`.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::both_present;
+///
+/// assert!(both_present(Some(1), Some(2)));
+/// assert!(!both_present(None, Some(2)));
+/// ```
+pub fn both_present(one: Option<i32>, two: Option<i32>) -> bool {
+    matches!((one, two), (Some(_), Some(_)))
+}
+
+/// `..` ignores the remaining parts of a value, as long as which
+/// fields are meant stays unambiguous.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::{first_third_fifth, first_and_last};
+///
+/// assert_eq!(first_third_fifth((1, 2, 3, 4, 5)), (1, 3, 5));
+/// assert_eq!(first_and_last((1, 2, 3)), (1, 3));
+/// ```
+pub fn first_third_fifth(numbers: (i32, i32, i32, i32, i32)) -> (i32, i32, i32) {
+    let (x, _, y, _, z) = numbers;
+    (x, y, z)
+}
+
+pub fn first_and_last(numbers: (i32, i32, i32)) -> (i32, i32) {
+    let (first, .., last) = numbers;
+    (first, last)
+}
+
+/// Match guards add an arbitrary `bool` condition to a pattern.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::describe_parity;
+///
+/// assert_eq!(describe_parity(Some(4)), "The number 4 is even.");
+/// assert_eq!(describe_parity(Some(5)), "The number 5 is odd.");
+/// assert_eq!(describe_parity(None), "No number given.");
+/// ```
+pub fn describe_parity(num: Option<i32>) -> String {
+    match num {
+        Some(k) if k % 2 == 0 => format!("The number {k} is even."),
+        Some(k) => format!("The number {k} is odd."),
+        None => "No number given.".to_string(),
+    }
+}
+
+/// A guard applies to the whole `|`-separated pattern, not just its
+/// last alternative.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::yes_if;
+///
+/// assert!(yes_if(5, true));
+/// assert!(!yes_if(5, false));
+/// assert!(!yes_if(7, true));
+/// ```
+// kept as `|`-separated alternatives rather than `4..=6` to show the
+// guard applying to the whole pattern, not just its last alternative
+#[allow(clippy::manual_range_patterns)]
+pub fn yes_if(x: i32, y: bool) -> bool {
+    matches!(x, 4 | 5 | 6 if y)
+}
+
+pub enum HelloMessage {
+    Hello { id: i32 },
+}
+
+/// `@` both tests a value against a pattern and binds the matched
+/// value to a name, combining what a plain range arm and a plain
+/// binding can each do on their own.
+///
+/// ```
+/// use prolangs_guide::pattern_matching::{describe_hello, HelloMessage};
+///
+/// assert_eq!(describe_hello(HelloMessage::Hello { id: 5 }), "in range: 5");
+/// assert_eq!(describe_hello(HelloMessage::Hello { id: 11 }), "another range");
+/// assert_eq!(describe_hello(HelloMessage::Hello { id: 20 }), "other id: 20");
+/// ```
+pub fn describe_hello(msg: HelloMessage) -> String {
+    match msg {
+        // matches only when id is in 3..=7, and id_variable is
+        // available in the body -- a plain `3..=7` couldn't be used
+        // here, and a plain `id` couldn't restrict the range
+        HelloMessage::Hello { id: id_variable @ 3..=7 } => {
+            format!("in range: {id_variable}")
+        }
+        HelloMessage::Hello { id: 10..=12 } => "another range".to_string(),
+        HelloMessage::Hello { id } => format!("other id: {id}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dice_roll_catch_all() {
+        fn move_player(spaces: i32) -> i32 {
+            spaces
+        }
+
+        let dice_roll = 4;
+        let result = match dice_roll {
+            2 => 0,
+            3 => 0,
+            other => move_player(other),
+        };
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn function_parameter_pattern() {
+        assert_eq!(sum_pair(&(2, 3)), 5);
+    }
+
+    #[test]
+    fn ignoring_a_whole_parameter() {
+        assert_eq!(only_uses_y(3, 4), 4);
+    }
+
+    #[test]
+    fn nested_tuple_and_struct_destructuring() {
+        let (feet_inches, point) = feet_and_point();
+        assert_eq!(feet_inches, (5, 11));
+        assert_eq!(point, Point { x: 12, y: 14 });
+    }
+}
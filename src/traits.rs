@@ -0,0 +1,160 @@
+//! Traits and trait objects.
+//!
+//! Traits are Rust's take on shared behavior, similar to interfaces in
+//! Java. A type can implement a trait by providing concrete bodies for
+//! its method signatures.
+
+/// A trait with one method, which has a default implementation:
+/// `Person` overrides it, `Robot` keeps the default.
+///
+/// ```
+/// use prolangs_guide::traits::{Greet, Person};
+///
+/// let person = Person { name: "Rust".to_string() };
+/// assert_eq!(person.say_hello(), "My name is Rust");
+/// ```
+pub trait Greet {
+    fn say_hello(&self) -> String {
+        "Default hello".to_string()
+    }
+}
+
+pub struct Person {
+    pub name: String,
+}
+
+impl Greet for Person {
+    fn say_hello(&self) -> String {
+        format!("My name is {}", self.name)
+    }
+}
+
+pub struct Robot;
+
+// uses the trait's default implementation
+impl Greet for Robot {}
+
+pub enum Color {
+    Red,
+    Blue,
+    Green,
+}
+
+impl Greet for Color {
+    fn say_hello(&self) -> String {
+        match self {
+            Color::Red => "Red".to_string(),
+            Color::Blue => "Blue".to_string(),
+            Color::Green => "Green".to_string(),
+        }
+    }
+}
+
+/// Static dispatch: the compiler generates specialized code for each
+/// concrete type that calls this function at compile time
+/// (monomorphization).
+///
+/// ```
+/// use prolangs_guide::traits::{greet_someone, Person};
+///
+/// let person = Person { name: "Rust".to_string() };
+/// greet_someone(&person);
+/// ```
+pub fn greet_someone(greeter: &impl Greet) {
+    println!("{}", greeter.say_hello());
+}
+
+/// Dynamic dispatch via a trait object: which method runs is resolved
+/// at runtime through a vtable, one step slower than static dispatch
+/// but able to hold many concrete types behind one pointer type.
+///
+/// ```
+/// use prolangs_guide::traits::{dynamic_greet, Person};
+///
+/// let person = Person { name: "Rust".to_string() };
+/// dynamic_greet(&person);
+/// ```
+pub fn dynamic_greet(greeter: &dyn Greet) {
+    println!("{}", greeter.say_hello());
+}
+
+/// `impl Trait` in return position: the caller only knows the return
+/// value implements `Greet`, not which concrete type it is.
+///
+/// ```
+/// use prolangs_guide::traits::{dynamic_greet, get_greeter};
+///
+/// let bob = get_greeter("Bob");
+/// dynamic_greet(&bob);
+/// ```
+pub fn get_greeter(name: &str) -> impl Greet {
+    Person { name: name.to_string() }
+}
+
+pub trait Animal {
+    fn speak(&self) -> String;
+}
+
+pub struct Dog;
+pub struct Cat;
+
+impl Animal for Dog {
+    fn speak(&self) -> String {
+        "Woof!".to_string()
+    }
+}
+
+impl Animal for Cat {
+    fn speak(&self) -> String {
+        "Meow!".to_string()
+    }
+}
+
+/// `Box<dyn Trait>` owns a heap-allocated value of unknown concrete
+/// type, which makes it possible to store a heterogeneous collection
+/// of types that all implement the same trait.
+///
+/// ```
+/// use prolangs_guide::traits::{Animal, Cat, Dog};
+///
+/// let animals: Vec<Box<dyn Animal>> = vec![Box::new(Dog), Box::new(Cat)];
+/// let sounds: Vec<String> = animals.iter().map(|a| a.speak()).collect();
+/// assert_eq!(sounds, vec!["Woof!", "Meow!"]);
+/// ```
+pub fn speak_all(animals: &[Box<dyn Animal>]) -> Vec<String> {
+    animals.iter().map(|a| a.speak()).collect()
+}
+
+// Use Case      Static Dispatch (impl Trait)              Dynamic Dispatch (dyn Trait)
+// Performance   Faster (compile-time resolution)          Slightly slower (runtime lookup)
+// Flexibility   Less flexible (types known at compile     More flexible (heterogeneous
+//               time)                                     collections)
+// Binary Size   Larger (due to monomorphization)           Smaller (one implementation)
+// Use Case      When types are known at compile time       When types vary at runtime
+//                                                           (e.g., plugins)
+
+// Alternatives to Box<dyn Trait>:
+// Approach      Pros                          Cons
+// impl Trait    Zero-cost, no heap allocation Less flexible (compile-time)
+// &dyn Trait    No allocation, borrows data   Lifetime management harder
+// Enums         Faster, no heap               Must know all variants upfront
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dynamic_dispatch_over_heterogeneous_collection() {
+        let alice = Person { name: "Alice".to_string() };
+        let bot = Robot;
+        let col = Color::Green;
+
+        let greeters: Vec<&dyn Greet> = vec![&alice, &bot, &col];
+        let messages: Vec<String> = greeters.iter().map(|g| g.say_hello()).collect();
+
+        assert_eq!(
+            messages,
+            vec!["My name is Alice", "Default hello", "Green"]
+        );
+    }
+}